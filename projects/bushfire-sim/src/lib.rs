@@ -1,6 +1,10 @@
 use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
 use rayon::prelude::*;
 use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::f64::consts::PI;
 
 /// Cell states in the bushfire simulation
 #[derive(Clone, Copy, PartialEq)]
@@ -22,17 +26,94 @@ impl From<u8> for CellState {
     }
 }
 
+/// Base rate of spread before wind, slope and moisture modifiers
+const BASE_RATE_OF_SPREAD: f64 = 0.35;
+/// Heat released per unit of fuel consumed, used to scale intensity
+const HEAT_YIELD: f64 = 18000.0;
+/// Scales flame residence time against the fuel's surface-area-to-volume ratio
+const RESIDENCE_CONSTANT: f64 = 15000.0;
+
+/// Per-cell fuel properties for the simplified Rothermel spread model
+///
+/// A cell keeps burning until its fuel is exhausted or its flame residence
+/// time `tau` elapses, rather than flipping straight to Burnt in one step.
+#[derive(Clone, Copy)]
+struct FuelCell {
+    load: f64,                   // remaining fuel load (kg/m^2)
+    sav: f64,                    // surface-area-to-volume ratio (1/m)
+    moisture: f64,               // fuel moisture fraction
+    moisture_of_extinction: f64, // moisture above which the cell can't ignite
+    tau: u32,                    // flame residence time in steps (0 until ignited)
+    burning_steps: u32,          // steps spent burning so far
+    consumption_rate: f64,       // fuel burned per step once alight
+    intensity: f64,              // accumulated heat release (fuel consumed x yield)
+}
+
+impl Default for FuelCell {
+    fn default() -> Self {
+        FuelCell {
+            load: 1.5,
+            sav: 2000.0,
+            moisture: 0.08,
+            moisture_of_extinction: 0.3,
+            tau: 0,
+            burning_steps: 0,
+            consumption_rate: 0.0,
+            intensity: 0.0,
+        }
+    }
+}
+
+/// Derive rate-of-spread and residence time for a freshly ignited cell
+fn ignite_fuel(fuel: &mut FuelCell, wind_speed: f64) {
+    // A workable simplification of Rothermel: wind and slope scale the base
+    // rate while moisture damps it by exp(-moisture / moisture_of_extinction).
+    let phi_w = wind_speed / 120.0;
+    let phi_s = 0.0; // slope factor (flat terrain by default)
+    let rate = BASE_RATE_OF_SPREAD
+        * (1.0 + phi_w + phi_s)
+        * (-fuel.moisture / fuel.moisture_of_extinction).exp();
+
+    fuel.consumption_rate = rate;
+    fuel.tau = ((RESIDENCE_CONSTANT / fuel.sav).ceil() as u32).max(1);
+    fuel.burning_steps = 0;
+}
+
 /// Bushfire simulation engine using cellular automata
 #[pyclass]
 pub struct FireSimulation {
     width: usize,
     height: usize,
-    grid: Vec<Vec<CellState>>,
+    grid: Vec<u8>,        // flat row-major cell states
+    back: Vec<u8>,        // preallocated back buffer, swapped each step
+    fuel: Vec<FuelCell>,  // flat row-major fuel grid
+    frontier: Vec<usize>, // active cells (Burning + Vegetation neighbors) to process
+    visited: Vec<u32>,    // per-cell generation stamp used to dedup the frontier
+    sval: u32,            // monotonically increasing frontier generation counter
     wind_speed: f64,
     wind_direction: f64, // radians
     humidity: f64,
     temperature: f64,
     step: u32,
+    spotting_enabled: bool,
+    spot_max_distance: f64,
+    spot_probability_scale: f64,
+    seed: u64, // base seed for reproducible ember spotting
+}
+
+/// Add a cell to the next frontier, deduplicating via the generation stamp
+fn enqueue(visited: &mut [u32], next: &mut Vec<usize>, gen: u32, idx: usize) {
+    if visited[idx] != gen {
+        visited[idx] = gen;
+        next.push(idx);
+    }
+}
+
+/// Outcome of evaluating a single cell during a step
+enum CellUpdate {
+    Ignite,  // Vegetation catches fire
+    Consume, // Burning cell keeps burning and consumes fuel
+    Burnout, // Burning cell has exhausted its fuel or residence time
 }
 
 #[pymethods]
@@ -46,88 +127,168 @@ impl FireSimulation {
         humidity: f64,
         temperature: f64,
     ) -> Self {
-        let mut grid = vec![vec![CellState::Empty; width]; height];
-        
+        let mut grid = vec![CellState::Empty as u8; width * height];
+        let fuel = vec![FuelCell::default(); width * height];
+
         // Initialize with random vegetation (higher density for better spread)
         let mut rng = rand::thread_rng();
-        for row in &mut grid {
-            for cell in row {
-                if rng.gen::<f64>() < 0.85 {  // Higher vegetation density
-                    *cell = CellState::Vegetation;
-                }
+        for cell in &mut grid {
+            if rng.gen::<f64>() < 0.85 {  // Higher vegetation density
+                *cell = CellState::Vegetation as u8;
             }
         }
 
         FireSimulation {
             width,
             height,
+            back: grid.clone(),
             grid,
+            fuel,
+            frontier: Vec::new(),
+            visited: vec![u32::MAX; width * height],
+            sval: 0,
             wind_speed,
             wind_direction,
             humidity,
             temperature,
             step: 0,
+            spotting_enabled: false,
+            spot_max_distance: 0.0,
+            spot_probability_scale: 1.0,
+            seed: 0,
         }
     }
 
     /// Start a fire at specified coordinates
     fn ignite(&mut self, x: usize, y: usize) -> PyResult<()> {
         if x < self.width && y < self.height {
-            if self.grid[y][x] == CellState::Vegetation {
-                self.grid[y][x] = CellState::Burning;
+            let idx = y * self.width + x;
+            if self.grid[idx] == CellState::Vegetation as u8 {
+                // Write both buffers so the step() resync invariant (grid == back
+                // at the start of each step) holds for externally seeded fires.
+                self.grid[idx] = CellState::Burning as u8;
+                self.back[idx] = CellState::Burning as u8;
+                ignite_fuel(&mut self.fuel[idx], self.wind_speed);
+                self.seed_frontier(x, y);
             }
         }
         Ok(())
     }
 
     /// Run one simulation step - this is where Rust shines with performance
+    ///
+    /// Only the active frontier (Burning cells plus their Vegetation neighbors)
+    /// is examined, so per-step cost tracks the fire front rather than the whole
+    /// grid. The contiguous back buffer is swapped in rather than reallocated.
     fn step(&mut self) -> PyResult<()> {
-        let mut new_grid = self.grid.clone();
-        
+        // The two buffers start each step identical, so rather than memcpy the
+        // whole grid into the back buffer we only restore the frontier-touched
+        // cells after swapping (see below). Per-step cost stays proportional to
+        // the active front, not the whole grid.
+
         // Capture needed values for parallel processing
         let width = self.width;
         let height = self.height;
         let grid = &self.grid;
+        let fuel = &self.fuel;
+        let frontier = &self.frontier;
         let wind_speed = self.wind_speed;
+        let wind_direction = self.wind_direction;
         let humidity = self.humidity;
         let temperature = self.temperature;
-        
-        // Process all cells in parallel using Rayon
-        let updates: Vec<_> = (0..height)
-            .into_par_iter()
-            .flat_map(|y| {
-                (0..width).into_par_iter().filter_map(move |x| {
-                    process_cell_static(grid, x, y, width, height, wind_speed, humidity, temperature)
-                        .map(|new_state| (x, y, new_state))
-                })
+        let spotting = self.spotting_enabled;
+        let max_distance = self.spot_max_distance;
+        let prob_scale = self.spot_probability_scale;
+        let seed = self.seed;
+        let step_idx = self.step;
+
+        // Process only the frontier in parallel, emitting both the cell's own
+        // transition and any ember-spotting ignitions it throws downwind.
+        let updates: Vec<_> = frontier
+            .par_iter()
+            .flat_map(move |&idx| {
+                let (x, y) = (idx % width, idx / width);
+                let mut out: Vec<(usize, CellUpdate)> = Vec::new();
+                if let Some(update) = process_cell_static(
+                    grid, fuel, x, y, width, height, wind_speed, wind_direction, humidity, temperature,
+                ) {
+                    let burning = matches!(update, CellUpdate::Consume | CellUpdate::Burnout);
+                    out.push((idx, update));
+                    if spotting && burning {
+                        if let Some((lx, ly)) = spot_landing(
+                            grid, fuel, x, y, width, height, wind_speed, wind_direction,
+                            max_distance, prob_scale, seed, step_idx,
+                        ) {
+                            out.push((ly * width + lx, CellUpdate::Ignite));
+                        }
+                    }
+                }
+                out
             })
             .collect();
 
-        // Apply updates
-        for (x, y, new_state) in updates {
-            new_grid[y][x] = new_state;
+        // Bump the generation stamp so this step's frontier dedups cleanly.
+        self.sval += 1;
+        let gen = self.sval;
+        let mut next_frontier: Vec<usize> = Vec::new();
+        let mut touched: Vec<usize> = Vec::new();
+
+        // Apply updates, mutating the fuel grid and rebuilding the frontier.
+        for (idx, update) in updates {
+            let (x, y) = (idx % width, idx / width);
+            match update {
+                CellUpdate::Ignite => {
+                    self.back[idx] = CellState::Burning as u8;
+                    touched.push(idx);
+                    ignite_fuel(&mut self.fuel[idx], wind_speed);
+                    self.enqueue_neighbors(&mut next_frontier, gen, x, y);
+                }
+                CellUpdate::Consume => {
+                    let cell = &mut self.fuel[idx];
+                    cell.load -= cell.consumption_rate;
+                    cell.intensity += cell.consumption_rate * HEAT_YIELD;
+                    cell.burning_steps += 1;
+                    self.enqueue_neighbors(&mut next_frontier, gen, x, y);
+                }
+                CellUpdate::Burnout => {
+                    let cell = &mut self.fuel[idx];
+                    cell.intensity += cell.load.max(0.0) * HEAT_YIELD;
+                    cell.load = 0.0;
+                    cell.burning_steps += 1;
+                    self.back[idx] = CellState::Burnt as u8;
+                    touched.push(idx);
+                }
+            }
         }
 
-        self.grid = new_grid;
+        // The new state is in `back`; swap it in, then carry those same
+        // transitions back into the old buffer so both stay in sync without a
+        // full-grid copy. Only frontier-touched cells are restored, keeping the
+        // per-step cost proportional to the active front.
+        std::mem::swap(&mut self.grid, &mut self.back);
+        for idx in touched {
+            self.back[idx] = self.grid[idx];
+        }
+        self.frontier = next_frontier;
         self.step += 1;
         Ok(())
     }
 
     /// Get current grid state as flat array for Python
     fn get_state(&self) -> Vec<u8> {
-        self.grid
-            .iter()
-            .flat_map(|row| row.iter().map(|&cell| cell as u8))
-            .collect()
+        self.grid.clone()
+    }
+
+    /// Get per-cell fire intensity as a flat array for heat maps
+    fn get_intensity(&self) -> Vec<f64> {
+        self.fuel.iter().map(|cell| cell.intensity).collect()
     }
 
     /// Get simulation statistics
     fn get_stats(&self) -> (u32, u32, u32, u32, u32) {
         let mut counts = [0u32; 4];
-        for row in &self.grid {
-            for &cell in row {
-                counts[cell as usize] += 1;
-            }
+        for &cell in &self.grid {
+            counts[cell as usize] += 1;
         }
         (self.step, counts[0], counts[1], counts[2], counts[3])
     }
@@ -139,35 +300,289 @@ impl FireSimulation {
         self.humidity = humidity;
         self.temperature = temperature;
     }
+
+    /// Configure ember spotting, which lets fire jump unvegetated gaps downwind
+    fn set_spotting(&mut self, enabled: bool, max_distance: f64, probability_scale: f64) {
+        self.spotting_enabled = enabled;
+        self.spot_max_distance = max_distance;
+        self.spot_probability_scale = probability_scale;
+    }
+
+    /// Set the base seed so ember spotting is reproducible across runs
+    fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Build a simulation from a deterministic scenario file
+    ///
+    /// The grid, wind direction and ignition points are read from `path`
+    /// instead of being seeded randomly, so runs can be checked in and shared.
+    #[staticmethod]
+    fn from_scenario(path: &str, wind_speed: f64, humidity: f64, temperature: f64) -> PyResult<Self> {
+        let scenario = read_scenario(path)?;
+
+        let cell_count = scenario.width * scenario.height;
+        let grid = scenario.cells.clone();
+        let fuel = vec![FuelCell::default(); cell_count];
+
+        let mut sim = FireSimulation {
+            width: scenario.width,
+            height: scenario.height,
+            back: grid.clone(),
+            grid,
+            fuel,
+            frontier: Vec::new(),
+            visited: vec![u32::MAX; cell_count],
+            sval: 0,
+            wind_speed,
+            wind_direction: scenario.wind_direction,
+            humidity,
+            temperature,
+            step: 0,
+            spotting_enabled: false,
+            spot_max_distance: 0.0,
+            spot_probability_scale: 1.0,
+            seed: 0,
+        };
+
+        // Cells that start Burning in the file get a fuel profile and seed the frontier
+        for y in 0..sim.height {
+            for x in 0..sim.width {
+                let idx = y * sim.width + x;
+                if sim.grid[idx] == CellState::Burning as u8 {
+                    ignite_fuel(&mut sim.fuel[idx], sim.wind_speed);
+                    sim.seed_frontier(x, y);
+                }
+            }
+        }
+
+        for (x, y) in scenario.ignition_points {
+            sim.ignite(x, y)?;
+        }
+
+        Ok(sim)
+    }
+
+    /// Write the current grid to a scenario file that `from_scenario` can reload
+    fn save_scenario(&self, path: &str) -> PyResult<()> {
+        let mut out = String::new();
+        out.push_str(&format!("{} {}\n", self.width, self.height));
+        for y in 0..self.height {
+            let row = &self.grid[y * self.width..(y + 1) * self.width];
+            let codes: Vec<String> = row.iter().map(|&cell| cell.to_string()).collect();
+            out.push_str(&codes.join(","));
+            out.push('\n');
+        }
+
+        // Serialize the currently burning cells as the ignition points.
+        let mut ignitions = Vec::new();
+        for (idx, &cell) in self.grid.iter().enumerate() {
+            if cell == CellState::Burning as u8 {
+                ignitions.push((idx % self.width, idx / self.width));
+            }
+        }
+
+        out.push_str(&format!("{}\n", ignitions.len()));
+        out.push_str(&format!("{}\n", radians_to_compass(self.wind_direction)));
+        for (x, y) in ignitions {
+            out.push_str(&format!("{},{}\n", x, y));
+        }
+
+        std::fs::write(path, out)
+            .map_err(|e| PyValueError::new_err(format!("failed to write scenario file: {e}")))
+    }
+}
+
+/// Parsed contents of a scenario file
+struct Scenario {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>, // row-major cell codes
+    ignition_points: Vec<(usize, usize)>,
+    wind_direction: f64, // radians
+}
+
+/// Read and validate a line-based scenario file
+fn read_scenario(path: &str) -> PyResult<Scenario> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| PyValueError::new_err(format!("failed to read scenario file: {e}")))?;
+    let mut lines = contents.lines().map(str::trim).filter(|l| !l.is_empty());
+
+    let header = lines
+        .next()
+        .ok_or_else(|| PyValueError::new_err("scenario file is empty"))?;
+    let dims: Vec<usize> = header
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<usize>()
+                .map_err(|_| PyValueError::new_err(format!("invalid grid dimension: {s}")))
+        })
+        .collect::<PyResult<_>>()?;
+    if dims.len() != 2 {
+        return Err(PyValueError::new_err(
+            "first line must be grid width and height",
+        ));
+    }
+    let (width, height) = (dims[0], dims[1]);
+    if width == 0 || height == 0 {
+        return Err(PyValueError::new_err("grid dimensions must be non-zero"));
+    }
+
+    let mut cells = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let row = lines
+            .next()
+            .ok_or_else(|| PyValueError::new_err(format!("missing row {y} of grid")))?;
+        let codes: Vec<&str> = row.split(',').map(str::trim).collect();
+        if codes.len() != width {
+            return Err(PyValueError::new_err(format!(
+                "row {y} has {} cells, expected {width}",
+                codes.len()
+            )));
+        }
+        for code in codes {
+            let value: u8 = code
+                .parse()
+                .map_err(|_| PyValueError::new_err(format!("invalid cell code: {code}")))?;
+            if value > 3 {
+                return Err(PyValueError::new_err(format!(
+                    "cell code {value} out of range (0-3)"
+                )));
+            }
+            cells.push(value);
+        }
+    }
+
+    let count_line = lines
+        .next()
+        .ok_or_else(|| PyValueError::new_err("missing ignition point count"))?;
+    let count: usize = count_line
+        .parse()
+        .map_err(|_| PyValueError::new_err(format!("invalid ignition count: {count_line}")))?;
+
+    let wind_line = lines
+        .next()
+        .ok_or_else(|| PyValueError::new_err("missing wind direction"))?;
+    let wind_direction = compass_to_radians(wind_line)?;
+
+    let mut ignition_points = Vec::with_capacity(count);
+    for i in 0..count {
+        let coord = lines
+            .next()
+            .ok_or_else(|| PyValueError::new_err(format!("missing ignition point {i}")))?;
+        let parts: Vec<&str> = coord.split(',').map(str::trim).collect();
+        if parts.len() != 2 {
+            return Err(PyValueError::new_err(format!(
+                "ignition point {i} must be `x,y`"
+            )));
+        }
+        let x: usize = parts[0]
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("invalid ignition x: {}", parts[0])))?;
+        let y: usize = parts[1]
+            .parse()
+            .map_err(|_| PyValueError::new_err(format!("invalid ignition y: {}", parts[1])))?;
+        if x >= width || y >= height {
+            return Err(PyValueError::new_err(format!(
+                "ignition point ({x},{y}) is outside the grid"
+            )));
+        }
+        ignition_points.push((x, y));
+    }
+
+    Ok(Scenario {
+        width,
+        height,
+        cells,
+        ignition_points,
+        wind_direction,
+    })
+}
+
+/// Map a compass letter to the `wind_direction` radians field
+fn compass_to_radians(letter: &str) -> PyResult<f64> {
+    let radians = match letter.to_uppercase().as_str() {
+        "E" => 0.0,
+        "NE" => PI / 4.0,
+        "N" => PI / 2.0,
+        "NW" => 3.0 * PI / 4.0,
+        "W" => PI,
+        "SW" => 5.0 * PI / 4.0,
+        "S" => 3.0 * PI / 2.0,
+        "SE" => 7.0 * PI / 4.0,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "unknown wind direction: {other}"
+            )))
+        }
+    };
+    Ok(radians)
+}
+
+/// Map radians back to the nearest compass letter for serialization
+fn radians_to_compass(radians: f64) -> &'static str {
+    let letters = ["E", "NE", "N", "NW", "W", "SW", "S", "SE"];
+    let step = 2.0 * PI / 8.0;
+    let normalized = radians.rem_euclid(2.0 * PI);
+    let index = (normalized / step).round() as usize % 8;
+    letters[index]
+}
+
+/// Parse a scenario file into its grid, ignition points and wind direction
+#[pyfunction]
+fn parse_scenario(path: &str) -> PyResult<(usize, usize, Vec<u8>, Vec<(usize, usize)>, f64)> {
+    let scenario = read_scenario(path)?;
+    Ok((
+        scenario.width,
+        scenario.height,
+        scenario.cells,
+        scenario.ignition_points,
+        scenario.wind_direction,
+    ))
 }
 
 /// Static version of process_cell for parallel processing
+#[allow(clippy::too_many_arguments)]
 fn process_cell_static(
-    grid: &Vec<Vec<CellState>>, 
-    x: usize, 
-    y: usize, 
-    width: usize, 
+    grid: &[u8],
+    fuel: &[FuelCell],
+    x: usize,
+    y: usize,
+    width: usize,
     height: usize,
     wind_speed: f64,
+    wind_direction: f64,
     humidity: f64,
     temperature: f64
-) -> Option<CellState> {
-    let current = grid[y][x];
-    
+) -> Option<CellUpdate> {
+    let idx = y * width + x;
+    let current = CellState::from(grid[idx]);
+
     match current {
         CellState::Burning => {
-            // Burning cells become burnt
-            Some(CellState::Burnt)
+            // Burn down fuel over multiple steps instead of flipping instantly
+            let cell = &fuel[idx];
+            if cell.load - cell.consumption_rate <= 0.0 || cell.burning_steps + 1 >= cell.tau {
+                Some(CellUpdate::Burnout)
+            } else {
+                Some(CellUpdate::Consume)
+            }
         }
         CellState::Vegetation => {
+            // Cells wetter than their moisture-of-extinction never ignite
+            if fuel[idx].moisture > fuel[idx].moisture_of_extinction {
+                return None;
+            }
+
             // Check if vegetation should catch fire
             let fire_probability = calculate_fire_probability_static(
-                grid, x, y, width, height, wind_speed, humidity, temperature
+                grid, x, y, width, height, wind_speed, wind_direction, humidity, temperature
             );
             let mut rng = rand::thread_rng();
-            
+
             if rng.gen::<f64>() < fire_probability {
-                Some(CellState::Burning)
+                Some(CellUpdate::Ignite)
             } else {
                 None
             }
@@ -176,152 +591,200 @@ fn process_cell_static(
     }
 }
 
+/// Decide whether a burning cell throws an ember and where it lands
+///
+/// Spotting fires with a probability that grows with wind speed and the cell's
+/// accumulated intensity; the landing distance is drawn from an exponential
+/// whose mean scales with wind speed, projected along the wind vector with a
+/// small Gaussian lateral jitter. Returns the target cell when the ember lands
+/// on in-bounds Vegetation that is dry enough to ignite.
+#[allow(clippy::too_many_arguments)]
+fn spot_landing(
+    grid: &[u8],
+    fuel: &[FuelCell],
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    wind_speed: f64,
+    wind_direction: f64,
+    max_distance: f64,
+    probability_scale: f64,
+    seed: u64,
+    step_idx: u32,
+) -> Option<(usize, usize)> {
+    // Derive a per-cell RNG so spotting is reproducible yet parallel-safe.
+    let cell_seed = seed
+        ^ (step_idx as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ ((x as u64) << 32)
+        ^ (y as u64).wrapping_mul(0x632B_E59B_D9B4_E019);
+    let mut rng = StdRng::seed_from_u64(cell_seed);
+
+    let intensity_factor = (fuel[y * width + x].intensity / HEAT_YIELD).min(1.0);
+    let spot_prob = (probability_scale * (wind_speed / 120.0) * intensity_factor).min(1.0);
+    if rng.gen::<f64>() >= spot_prob {
+        return None;
+    }
+
+    // Exponential landing distance via inverse transform, capped at max_distance.
+    let mean = (max_distance * (wind_speed / 120.0)).max(1e-6);
+    let u: f64 = rng.gen();
+    let distance = (-mean * (1.0 - u).ln()).min(max_distance);
+
+    // Box-Muller Gaussian jitter perpendicular to the wind.
+    let u1 = rng.gen::<f64>().max(1e-12);
+    let u2: f64 = rng.gen();
+    let jitter = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+
+    let (wx, wy) = (wind_direction.cos(), wind_direction.sin());
+    let (px, py) = (-wy, wx); // unit vector perpendicular to the wind
+    let fx = x as f64 + distance * wx + jitter * px;
+    let fy = y as f64 + distance * wy + jitter * py;
+    if fx < 0.0 || fy < 0.0 {
+        return None;
+    }
+
+    let (lx, ly) = (fx.round() as usize, fy.round() as usize);
+    if lx >= width || ly >= height {
+        return None;
+    }
+    let lidx = ly * width + lx;
+    if grid[lidx] != CellState::Vegetation as u8 {
+        return None;
+    }
+    if fuel[lidx].moisture > fuel[lidx].moisture_of_extinction {
+        return None;
+    }
+
+    Some((lx, ly))
+}
+
 /// Static version of calculate_fire_probability
+#[allow(clippy::too_many_arguments)]
 fn calculate_fire_probability_static(
-    grid: &Vec<Vec<CellState>>, 
-    x: usize, 
-    y: usize, 
-    width: usize, 
+    grid: &[u8],
+    x: usize,
+    y: usize,
+    width: usize,
     height: usize,
     wind_speed: f64,
+    wind_direction: f64,
     humidity: f64,
     temperature: f64
 ) -> f64 {
-    // Count burning neighbors
-    let burning_neighbors = count_burning_neighbors_static(grid, x, y, width, height);
-    if burning_neighbors == 0 {
+    // Weighted count of burning neighbors, biased downwind
+    let weighted_sum = count_burning_neighbors_static(grid, x, y, width, height, wind_speed, wind_direction);
+    if weighted_sum == 0.0 {
         return 0.0;
     }
 
     // Balanced base rate
     let base_rate = 0.35;
-    
+
     // Moderate wind effect
     let wind_factor = 1.0 + (wind_speed / 120.0);
-    
+
     // Balanced humidity effect
     let humidity_factor = 1.15 - (humidity / 100.0);
-    
+
     // Moderate temperature effect
     let temp_factor = 0.85 + (temperature / 120.0);
-    
-    // Good neighbor effect
-    let neighbor_factor = burning_neighbors as f64 * 0.45;
+
+    // Good neighbor effect, weighted by wind alignment
+    let neighbor_factor = 0.45 * weighted_sum;
 
     let probability = base_rate * wind_factor * humidity_factor * temp_factor * neighbor_factor;
-    
+
     // Balanced caps for controlled but visible spread
     let max_prob = if wind_speed > 65.0 { 0.7 } else { 0.6 };
     probability.min(max_prob)
 }
 
+/// Sum burning neighbors, weighting each by how well the wind pushes fire
+/// from that neighbor toward `(x, y)` so downwind cells spread much harder.
 fn count_burning_neighbors_static(
-    grid: &Vec<Vec<CellState>>, 
-    x: usize, 
-    y: usize, 
-    width: usize, 
-    height: usize
-) -> u8 {
-    let mut count = 0;
-    
+    grid: &[u8],
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    wind_speed: f64,
+    wind_direction: f64
+) -> f64 {
+    let (wind_x, wind_y) = (wind_direction.cos(), wind_direction.sin());
+    let mut weighted = 0.0;
+
     for dy in -1i32..=1 {
         for dx in -1i32..=1 {
             if dx == 0 && dy == 0 {
                 continue;
             }
-            
+
             let nx = (x as i32 + dx) as usize;
             let ny = (y as i32 + dy) as usize;
-            
+
             if nx < width && ny < height {
-                if grid[ny][nx] == CellState::Burning {
-                    count += 1;
+                if grid[ny * width + nx] == CellState::Burning as u8 {
+                    // Unit vector from the burning neighbor toward the target cell
+                    let len = ((dx * dx + dy * dy) as f64).sqrt();
+                    let (ux, uy) = (-(dx as f64) / len, -(dy as f64) / len);
+                    let alignment = ux * wind_x + uy * wind_y;
+                    weighted += 1.0 + (wind_speed / 120.0) * alignment.max(0.0);
                 }
             }
         }
     }
-    
-    count
+
+    weighted
 }
 
 impl FireSimulation {
-    /// Process a single cell - core fire spread logic
-    fn process_cell(&self, x: usize, y: usize) -> Option<CellState> {
-        let current = self.grid[y][x];
-        
-        match current {
-            CellState::Burning => {
-                // Burning cells become burnt
-                Some(CellState::Burnt)
-            }
-            CellState::Vegetation => {
-                // Check if vegetation should catch fire
-                let fire_probability = self.calculate_fire_probability(x, y);
-                let mut rng = rand::thread_rng();
-                
-                if rng.gen::<f64>() < fire_probability {
-                    Some(CellState::Burning)
-                } else {
-                    None
-                }
-            }
-            _ => None, // Empty and Burnt cells don't change
+    /// Set the fuel moisture of every cell, e.g. to follow an hourly series
+    fn set_all_fuel_moisture(&mut self, moisture: f64) {
+        for cell in &mut self.fuel {
+            cell.moisture = moisture;
         }
     }
 
-    /// Calculate fire spread probability based on Australian fire behavior
-    fn calculate_fire_probability(&self, x: usize, y: usize) -> f64 {
-        let mut probability = 0.0;
-        
-        // Count burning neighbors
-        let burning_neighbors = self.count_burning_neighbors(x, y);
-        if burning_neighbors == 0 {
-            return 0.0;
+    /// Set the moisture-of-extinction threshold of every cell
+    fn set_all_extinction_moisture(&mut self, moisture_of_extinction: f64) {
+        for cell in &mut self.fuel {
+            cell.moisture_of_extinction = moisture_of_extinction;
         }
+    }
+
+    /// Seed the current frontier with a newly burning cell and its neighbors
+    fn seed_frontier(&mut self, x: usize, y: usize) {
+        let gen = self.sval;
+        let mut frontier = std::mem::take(&mut self.frontier);
+        self.enqueue_neighbors(&mut frontier, gen, x, y);
+        self.frontier = frontier;
+    }
+
+    /// Enqueue a burning cell plus its Vegetation/Burning neighbors for the next step
+    fn enqueue_neighbors(&mut self, next: &mut Vec<usize>, gen: u32, x: usize, y: usize) {
+        let idx = y * self.width + x;
+        enqueue(&mut self.visited, next, gen, idx);
 
-        // Balanced base rate
-        let base_rate = 0.35;
-        
-        // Moderate wind effect
-        let wind_factor = 1.0 + (self.wind_speed / 120.0);
-        
-        // Balanced humidity effect
-        let humidity_factor = 1.15 - (self.humidity / 100.0);
-        
-        // Moderate temperature effect
-        let temp_factor = 0.85 + (self.temperature / 120.0);
-        
-        // Good neighbor effect
-        let neighbor_factor = burning_neighbors as f64 * 0.45;
-
-        probability = base_rate * wind_factor * humidity_factor * temp_factor * neighbor_factor;
-        
-        // Balanced caps for controlled but visible spread
-        let max_prob = if self.wind_speed > 65.0 { 0.7 } else { 0.6 };
-        probability.min(max_prob)
-    }
-
-    fn count_burning_neighbors(&self, x: usize, y: usize) -> u8 {
-        let mut count = 0;
-        
         for dy in -1i32..=1 {
             for dx in -1i32..=1 {
                 if dx == 0 && dy == 0 {
                     continue;
                 }
-                
-                let nx = (x as i32 + dx) as usize;
-                let ny = (y as i32 + dy) as usize;
-                
-                if nx < self.width && ny < self.height {
-                    if self.grid[ny][nx] == CellState::Burning {
-                        count += 1;
-                    }
+
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+                if nx < 0 || ny < 0 || nx as usize >= self.width || ny as usize >= self.height {
+                    continue;
+                }
+
+                let nidx = ny as usize * self.width + nx as usize;
+                let state = self.grid[nidx];
+                if state == CellState::Vegetation as u8 || state == CellState::Burning as u8 {
+                    enqueue(&mut self.visited, next, gen, nidx);
                 }
             }
         }
-        
-        count
     }
 }
 
@@ -355,10 +818,123 @@ fn run_batch_simulation(
     Ok(results)
 }
 
+/// One hour of the weather series driving a fire-season simulation
+#[pyclass]
+#[derive(Clone)]
+struct HourRecord {
+    temperature: f64,
+    humidity: f64,
+    wind_speed: f64,
+    wind_direction: f64, // radians
+    fuel_moisture: f64,
+    lightning_strikes: u32,
+}
+
+#[pymethods]
+impl HourRecord {
+    #[new]
+    fn new(
+        temperature: f64,
+        humidity: f64,
+        wind_speed: f64,
+        wind_direction: f64,
+        fuel_moisture: f64,
+        lightning_strikes: u32,
+    ) -> Self {
+        HourRecord {
+            temperature,
+            humidity,
+            wind_speed,
+            wind_direction,
+            fuel_moisture,
+            lightning_strikes,
+        }
+    }
+}
+
+/// Run a stochastic fire-season simulation driven by an hourly weather series
+///
+/// Each hour applies its weather, replays any smouldering lightning strikes that
+/// are now dry enough to ignite, and may throw a fresh strike whose probability
+/// grows with the hour's strike count. A strike on fuel wetter than
+/// `extinction_moisture` only smoulders, igniting later once moisture drops
+/// within `smoulder_hours`. Returns the per-step grid states like
+/// `run_batch_simulation`.
+#[pyfunction]
+fn run_weather_scenario(
+    width: usize,
+    height: usize,
+    weather: Vec<HourRecord>,
+    extinction_moisture: f64,
+    smoulder_hours: u32,
+) -> PyResult<Vec<Vec<u8>>> {
+    let mut sim = FireSimulation::new(width, height, 0.0, 0.0, 50.0, 25.0);
+    sim.set_all_extinction_moisture(extinction_moisture);
+
+    // Seeded RNG for strike and smoulder selection only; the fire spread in
+    // `process_cell_static` still draws from `thread_rng`, so the returned grids
+    // are not reproducible run-to-run.
+    let mut rng = StdRng::seed_from_u64(sim.seed);
+
+    let mut results = Vec::with_capacity(weather.len() + 1);
+    results.push(sim.get_state());
+
+    // Strikes that hit damp fuel smoulder until it dries out or they go cold.
+    let mut smouldering: Vec<(usize, usize, usize)> = Vec::new();
+
+    for (hour, record) in weather.iter().enumerate() {
+        sim.set_weather(
+            record.wind_speed,
+            record.wind_direction,
+            record.humidity,
+            record.temperature,
+        );
+        sim.set_all_fuel_moisture(record.fuel_moisture);
+
+        // Revisit smouldering strikes now that this hour's moisture is known.
+        let mut still_smouldering = Vec::new();
+        for (sx, sy, struck) in smouldering.drain(..) {
+            if hour.saturating_sub(struck) > smoulder_hours as usize {
+                continue; // firebrand went cold
+            }
+            if record.fuel_moisture <= extinction_moisture {
+                sim.ignite(sx, sy)?;
+            } else {
+                still_smouldering.push((sx, sy, struck));
+            }
+        }
+        smouldering = still_smouldering;
+
+        // A fresh strike this hour, more likely the more strikes the hour logs.
+        // An empty grid has nowhere to strike, so skip sampling a location.
+        if record.lightning_strikes > 0 && width > 0 && height > 0 {
+            let lambda = record.lightning_strikes as f64;
+            let strike_prob = 1.0 - (-lambda).exp();
+            if rng.gen::<f64>() < strike_prob {
+                let x = rng.gen_range(0..width);
+                let y = rng.gen_range(0..height);
+                if record.fuel_moisture <= extinction_moisture {
+                    sim.ignite(x, y)?;
+                } else {
+                    smouldering.push((x, y, hour));
+                }
+            }
+        }
+
+        sim.step()?;
+        results.push(sim.get_state());
+    }
+
+    Ok(results)
+}
+
 /// A Python module implemented in Rust
 #[pymodule]
 fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<FireSimulation>()?;
+    m.add_class::<HourRecord>()?;
     m.add_function(wrap_pyfunction!(run_batch_simulation, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_scenario, m)?)?;
+    m.add_function(wrap_pyfunction!(run_weather_scenario, m)?)?;
     Ok(())
 }